@@ -0,0 +1,25 @@
+//! Error types used throughout the crate
+
+use font_kit::error::{FontLoadingError, GlyphLoadingError, SelectionError};
+use thiserror::Error;
+
+/// Errors that can occur while loading fonts or rasterizing glyphs
+#[derive(Debug, Error)]
+pub enum FontError {
+    /// No font was loaded for the requested family/style
+    #[error("no font loaded")]
+    MissingFont,
+
+    /// None of the configured fonts (nor the fallback) contain a glyph for this character
+    #[error("glyph not found for character `{0}`")]
+    MissingGlyph(char),
+
+    #[error("failed to select font family: {0}")]
+    Selection(#[from] SelectionError),
+
+    #[error("failed to load font: {0}")]
+    Loading(#[from] FontLoadingError),
+
+    #[error("failed to rasterize glyph: {0}")]
+    Glyph(#[from] GlyphLoadingError),
+}
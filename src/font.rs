@@ -9,7 +9,9 @@
 //! let mut image = RgbImage::new(250, 100);
 //! let font = FontCollection::new(&[("Hack", 27.0), ("FiraCode", 27.0)]).unwrap();
 //!
-//! font.draw_text_mut(&mut image, Rgb([255, 0, 0]), 0, 0, FontStyle::REGULAR, "Hello, world");
+//! font
+//!     .draw_text_mut(&mut image, Rgb([255, 0, 0]), 0, 0, FontStyle::REGULAR, "Hello, world")
+//!     .unwrap();
 //! ```
 use crate::error::FontError;
 use conv::ValueInto;
@@ -24,7 +26,7 @@ use image::{GenericImage, Pixel};
 use imageproc::definitions::Clamp;
 use imageproc::pixelops::weighted_sum;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use syntect::highlighting;
 
 /// Font style
@@ -54,11 +56,68 @@ impl From<highlighting::FontStyle> for FontStyle {
 
 use FontStyle::*;
 
+/// How to pick a face to render with: one of the four basic named styles, an
+/// explicit weight/slant description, or a sub-style looked up by name (e.g.
+/// "SemiBold", "Light") — anything [`ImageFont::get_by_style`],
+/// [`ImageFont::get_by_description`] or [`ImageFont::get_by_name`] can resolve.
+#[derive(Clone, Debug)]
+pub enum FontSelector {
+    Style(FontStyle),
+    Description { weight: f32, italic: bool },
+    Named(String),
+}
+
+impl From<FontStyle> for FontSelector {
+    fn from(style: FontStyle) -> Self {
+        FontSelector::Style(style)
+    }
+}
+
+/// Well-known system families likely to cover `c`, tried in order, cheapest
+/// (and most specific) first. Kept short and static on purpose: this is the
+/// whole point of the cascade, instead of loading every family on the system.
+fn fallback_candidates(c: char) -> &'static [&'static str] {
+    let codepoint = c as u32;
+
+    const EMOJI: &[&str] = &["Apple Color Emoji", "Noto Color Emoji", "Segoe UI Emoji"];
+    const CJK: &[&str] = &[
+        "PingFang SC",
+        "Hiragino Sans GB",
+        "Microsoft YaHei",
+        "Noto Sans CJK SC",
+        "Source Han Sans SC",
+    ];
+    const GENERIC: &[&str] = &["Arial Unicode MS", "Noto Sans", "DejaVu Sans"];
+
+    match codepoint {
+        0x2600..=0x27BF | 0x1F000..=0x1FFFF => EMOJI,
+        0x2E80..=0x9FFF | 0x3040..=0x30FF | 0xAC00..=0xD7A3 | 0xF900..=0xFAFF => CJK,
+        _ => GENERIC,
+    }
+}
+
+/// Whether `font`'s file declares a color-bitmap table (emoji, COLR). Checked
+/// once per [`ImageFont`] at load time rather than per glyph. Note this can
+/// only gate an informational fallback, not a real color composite: font-kit's
+/// public rasterizer (`GrayscaleAa`/`SubpixelAa` into an `Rgba32` canvas) never
+/// actually emits COLR/CBDT/sbix color pixels, so there is no API today to get
+/// a color glyph's real colors out of it.
+fn has_color_table(font: &Font) -> bool {
+    const COLOR_TABLES: [&[u8; 4]; 3] = [b"COLR", b"CBDT", b"sbix"];
+    COLOR_TABLES.iter().any(|&tag| font.load_font_table(tag).is_some())
+}
+
 /// A single font with specific size
 #[derive(Debug)]
 pub struct ImageFont {
     pub fonts: HashMap<FontStyle, Font>,
+    /// Every face the family shipped (Light, Medium, SemiBold, Oblique, ...),
+    /// kept so [`ImageFont::get_by_style`] can resolve to the nearest weight
+    /// instead of discarding anything that isn't Regular/Italic/Bold/BoldItalic.
+    faces: Vec<Font>,
     pub size: f32,
+    /// Whether the regular face carries a color table (see [`has_color_table`]).
+    is_color: bool,
 }
 
 impl Default for ImageFont {
@@ -83,12 +142,21 @@ impl Default for ImageFont {
             ),
         ];
         let mut fonts = HashMap::new();
+        let mut faces = vec![];
         for (style, bytes) in l {
             let font = Font::from_bytes(Arc::new(bytes), 0).unwrap();
+            faces.push(font.clone());
             fonts.insert(style, font);
         }
 
-        Self { fonts, size: 26.0 }
+        let is_color = has_color_table(&fonts[&REGULAR]);
+
+        Self {
+            fonts,
+            faces,
+            size: 26.0,
+            is_color,
+        }
     }
 }
 
@@ -102,6 +170,7 @@ impl ImageFont {
         }
 
         let mut fonts = HashMap::new();
+        let mut faces = vec![];
 
         let family = SystemSource::new().select_family_by_name(name)?;
         let handles = family.fonts();
@@ -119,33 +188,100 @@ impl ImageFont {
             match properties.style {
                 Style::Normal => {
                     if properties.weight == Weight::NORMAL {
-                        fonts.insert(REGULAR, font);
+                        fonts.insert(REGULAR, font.clone());
                     } else if properties.weight == Weight::BOLD {
-                        fonts.insert(BOLD, font);
+                        fonts.insert(BOLD, font.clone());
                     }
                 }
                 Style::Italic => {
                     if properties.weight == Weight::NORMAL {
-                        fonts.insert(ITALIC, font);
+                        fonts.insert(ITALIC, font.clone());
                     } else if properties.weight == Weight::BOLD {
-                        fonts.insert(BOLDITALIC, font);
+                        fonts.insert(BOLDITALIC, font.clone());
                     }
                 }
                 _ => (),
             }
+
+            faces.push(font);
         }
 
-        Ok(Self { fonts, size })
+        // `get_regular`/`get_by_description` assume a REGULAR face always
+        // exists; fail here instead of panicking on first use.
+        if !fonts.contains_key(&REGULAR) {
+            return Err(FontError::MissingFont);
+        }
+
+        let is_color = has_color_table(&fonts[&REGULAR]);
+
+        Ok(Self {
+            fonts,
+            faces,
+            size,
+            is_color,
+        })
     }
 
-    /// Get a font by style. If there is no such a font, it will return the REGULAR font.
+    /// Get a font by style. If there is no exact Regular/Italic/Bold/BoldItalic
+    /// match, resolves to the closest available weight instead of silently
+    /// falling back to REGULAR.
     pub fn get_by_style(&self, style: FontStyle) -> &Font {
-        self.fonts
-            .get(&style)
+        if let Some(font) = self.fonts.get(&style) {
+            return font;
+        }
+
+        let (weight, italic) = match style {
+            REGULAR => (Weight::NORMAL.0, false),
+            ITALIC => (Weight::NORMAL.0, true),
+            BOLD => (Weight::BOLD.0, false),
+            BOLDITALIC => (Weight::BOLD.0, true),
+        };
+
+        self.get_by_description(weight, italic)
+    }
+
+    /// Resolve the face whose weight is closest to `weight`, among the faces
+    /// matching the requested slant (or among all faces, if the family has
+    /// none of that slant). This lets callers pick a sub-style (e.g.
+    /// Light, Medium, SemiBold) that isn't one of the four basic [`FontStyle`]
+    /// variants. Falls back to the REGULAR font if nothing was loaded at all.
+    pub fn get_by_description(&self, weight: f32, italic: bool) -> &Font {
+        let closest = |a: &&Font, b: &&Font| {
+            let da = (a.properties().weight.0 - weight).abs();
+            let db = (b.properties().weight.0 - weight).abs();
+            da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+        };
+        self.faces
+            .iter()
+            .filter(|font| matches!(font.properties().style, Style::Italic | Style::Oblique) == italic)
+            .min_by(&closest)
+            .or_else(|| self.faces.iter().min_by(&closest))
             .unwrap_or_else(|| self.fonts.get(&REGULAR).unwrap())
     }
 
-    /// Get the regular font
+    /// Resolve a face by sub-style name (e.g. "SemiBold", "Light") via a
+    /// case-insensitive match against each face's full name.
+    pub fn get_by_name(&self, name: &str) -> Option<&Font> {
+        let name = name.to_lowercase();
+        self.faces
+            .iter()
+            .find(|font| font.full_name().to_lowercase().contains(&name))
+    }
+
+    /// Resolve a face from a [`FontSelector`], falling back to the REGULAR
+    /// font for a [`FontSelector::Named`] that doesn't match anything.
+    pub fn get_by_selector(&self, selector: &FontSelector) -> &Font {
+        match selector {
+            FontSelector::Style(style) => self.get_by_style(*style),
+            FontSelector::Description { weight, italic } => {
+                self.get_by_description(*weight, *italic)
+            }
+            FontSelector::Named(name) => self.get_by_name(name).unwrap_or_else(|| self.get_regular()),
+        }
+    }
+
+    /// Get the regular font. `ImageFont::new` refuses to construct an
+    /// instance without one, so this never panics.
     pub fn get_regular(&self) -> &Font {
         self.fonts.get(&REGULAR).unwrap()
     }
@@ -162,11 +298,33 @@ impl ImageFont {
 ///
 /// It can be used to draw text on the image.
 #[derive(Debug)]
-pub struct FontCollection(Vec<ImageFont>);
+pub struct FontCollection {
+    fonts: Vec<Arc<ImageFont>>,
+    /// Lazily-loaded system fonts used to cover characters none of `fonts` contain,
+    /// keyed by character so each fallback is resolved once (two threads that
+    /// hit the same uncovered character together may both resolve it). A `Mutex`
+    /// (rather than a `RefCell`) so `FontCollection` stays `Sync` and can be
+    /// shared across threads like it could before this cache existed.
+    fallback_cache: Mutex<HashMap<char, Option<Arc<ImageFont>>>>,
+    /// Extra space, in pixels, added between glyphs (`.0`) and folded into the
+    /// line height (`.1`) — tracking and leading, respectively.
+    offset: (i32, i32),
+    /// Scale factor applied to glyph size and canvas dimensions, e.g. `2.0`
+    /// for an @2x HiDPI export.
+    device_pixel_ratio: f32,
+    /// Use subpixel antialiasing (with hinting) instead of grayscale AA.
+    subpixel_aa: bool,
+}
 
 impl Default for FontCollection {
     fn default() -> Self {
-        Self(vec![ImageFont::default()])
+        Self {
+            fonts: vec![Arc::new(ImageFont::default())],
+            fallback_cache: Mutex::new(HashMap::new()),
+            offset: (0, 0),
+            device_pixel_ratio: 1.0,
+            subpixel_aa: false,
+        }
     }
 }
 
@@ -177,116 +335,277 @@ impl FontCollection {
         for (name, size) in font_list {
             let name = name.as_ref();
             match ImageFont::new(name, *size) {
-                Ok(font) => fonts.push(font),
+                Ok(font) => fonts.push(Arc::new(font)),
                 Err(err) => eprintln!("[error] Error occurs when load font `{}`: {}", name, err),
             }
         }
-        Ok(Self(fonts))
+        if fonts.is_empty() {
+            return Err(FontError::MissingFont);
+        }
+        Ok(Self {
+            fonts,
+            fallback_cache: Mutex::new(HashMap::new()),
+            offset: (0, 0),
+            device_pixel_ratio: 1.0,
+            subpixel_aa: false,
+        })
+    }
+
+    /// Set extra space (in pixels) added around each character: `(letter_spacing, line_spacing)`
+    pub fn set_offset(&mut self, offset: (i32, i32)) {
+        self.offset = offset;
+    }
+
+    /// Scale glyph rasterization and canvas dimensions by `ratio`, e.g. `2.0`
+    /// to produce a sharp @2x screenshot.
+    pub fn set_device_pixel_ratio(&mut self, ratio: f32) {
+        self.device_pixel_ratio = ratio;
+    }
+
+    /// Use subpixel antialiasing (with hinting) instead of grayscale AA, for
+    /// crisper text on high-resolution exports.
+    pub fn set_subpixel_aa(&mut self, enabled: bool) {
+        self.subpixel_aa = enabled;
+    }
+
+    /// Rasterization/hinting options to use for a glyph of the given (already
+    /// DPI-scaled) size, based on the configured antialiasing mode.
+    fn raster_options(&self, size: f32) -> (RasterizationOptions, HintingOptions) {
+        if self.subpixel_aa {
+            (RasterizationOptions::SubpixelAa, HintingOptions::Full(size))
+        } else {
+            (RasterizationOptions::GrayscaleAa, HintingOptions::None)
+        }
     }
 
-    fn glyph_for_char(&self, c: char, style: FontStyle) -> Option<(u32, &ImageFont, &Font)> {
-        for font in &self.0 {
-            let result = font.get_by_style(style);
+    fn glyph_for_char(
+        &self,
+        c: char,
+        selector: &FontSelector,
+    ) -> Option<(u32, Arc<ImageFont>, Font)> {
+        for font in &self.fonts {
+            let result = font.get_by_selector(selector);
             if let Some(id) = result.glyph_for_char(c) {
-                return Some((id, font, result));
+                return Some((id, Arc::clone(font), result.clone()));
             }
         }
-        eprintln!("[warning] No font found for character `{}`", c);
+
+        let fallback = self.fallback_font_for_char(c)?;
+        let result = fallback.get_by_selector(selector);
+        let id = result.glyph_for_char(c)?;
+        Some((id, fallback, result.clone()))
+    }
+
+    /// Find (and cache) a system font that covers `c`, for use when none of the
+    /// explicitly configured fonts contain the character.
+    fn fallback_font_for_char(&self, c: char) -> Option<Arc<ImageFont>> {
+        if let Some(cached) = self.fallback_cache.lock().unwrap().get(&c) {
+            return cached.clone();
+        }
+
+        // Resolve without holding the lock: `load_system_fallback` loads and
+        // parses font files, and we'd rather risk two threads racing to load
+        // the same fallback once than serialize every thread's rendering on
+        // whichever one hits an uncovered char first.
+        let size = self.fonts[0].size;
+        let found = Self::load_system_fallback(c, size);
+        self.fallback_cache.lock().unwrap().insert(c, found.clone());
+        found
+    }
+
+    /// Try each family in `fallback_candidates(c)`, in order, and return the
+    /// first face (in the family's own face order) that has a glyph for `c`.
+    /// Unlike scanning `SystemSource::all_families()`, this only ever loads
+    /// the handful of families actually likely to cover `c`.
+    fn load_system_fallback(c: char, size: f32) -> Option<Arc<ImageFont>> {
+        let source = SystemSource::new();
+
+        for family_name in fallback_candidates(c) {
+            let family = match source.select_family_by_name(family_name) {
+                Ok(family) => family,
+                Err(_) => continue,
+            };
+
+            for handle in family.fonts() {
+                let font = match handle.load() {
+                    Ok(font) => font,
+                    Err(_) => continue,
+                };
+
+                if font.glyph_for_char(c).is_none() {
+                    continue;
+                }
+
+                debug!("using fallback font `{}` for character `{}`", family_name, c);
+
+                let is_color = has_color_table(&font);
+                let mut fonts = HashMap::new();
+                fonts.insert(REGULAR, font.clone());
+                fonts.insert(ITALIC, font.clone());
+                fonts.insert(BOLD, font.clone());
+                fonts.insert(BOLDITALIC, font.clone());
+
+                return Some(Arc::new(ImageFont {
+                    fonts,
+                    faces: vec![font],
+                    size,
+                    is_color,
+                }));
+            }
+        }
+
         None
     }
 
-    /// get max height of all the fonts
+    /// get max height of all the fonts, scaled by the device pixel ratio and
+    /// with the configured line spacing folded in
     pub fn get_font_height(&self) -> u32 {
-        self.0
+        let height = self
+            .fonts
             .iter()
             .map(|font| font.get_font_height())
             .max()
-            .unwrap()
+            .unwrap();
+        let height = (height as f32 * self.device_pixel_ratio).ceil() as i32;
+        (height + self.offset.1).max(0) as u32
     }
 
-    fn layout(&self, text: &str, style: FontStyle) -> (Vec<PositionedGlyph>, u32) {
-        let mut delta_x = 0;
+    fn layout<S: Into<FontSelector>>(
+        &self,
+        text: &str,
+        style: S,
+    ) -> Result<(Vec<PositionedGlyph>, u32), FontError> {
+        let selector = style.into();
+        let mut delta_x: i32 = 0;
         let height = self.get_font_height();
 
-        let glyphs = text
-            .chars()
-            .filter_map(|c| {
-                self.glyph_for_char(c, style).map(|(id, imfont, font)| {
-                    let raster_rect = font
-                        .raster_bounds(
-                            id,
-                            imfont.size,
-                            &FontTransform::identity(),
-                            &Point2D::zero(),
-                            HintingOptions::None,
-                            RasterizationOptions::GrayscaleAa,
-                        )
-                        .unwrap();
-                    let x = delta_x as i32 + raster_rect.origin.x;
+        let mut glyphs = Vec::with_capacity(text.len());
+        for c in text.chars() {
+            match self.glyph_for_char(c, &selector) {
+                Some((id, imfont, font)) => {
+                    let size = imfont.size * self.device_pixel_ratio;
+                    let (raster_options, hinting) = self.raster_options(size);
+
+                    let raster_rect = font.raster_bounds(
+                        id,
+                        size,
+                        &FontTransform::identity(),
+                        &Point2D::zero(),
+                        hinting,
+                        raster_options,
+                    )?;
+                    let x = delta_x + raster_rect.origin.x;
                     let y = height as i32 - raster_rect.size.height - raster_rect.origin.y;
-                    delta_x += Self::get_glyph_width(font, id, imfont.size);
+                    delta_x += Self::get_glyph_width(&font, id, size)? as i32 + self.offset.0;
 
-                    PositionedGlyph {
+                    glyphs.push(PositionedGlyph {
                         id,
-                        font: font.clone(),
-                        size: imfont.size,
+                        font,
+                        size,
                         raster_rect,
                         position: Point2D::new(x, y),
-                    }
-                })
-            })
-            .collect();
+                        hinting,
+                        raster_options,
+                        is_color: imfont.is_color,
+                    });
+                }
+                // no font covers this character: skip it, but let the caller know why
+                None => eprintln!("[warning] {}", FontError::MissingGlyph(c)),
+            }
+        }
+
+        // `offset.0` above is tracking added *between* glyphs; the loop also
+        // adds it after the last one, so take it back out of the total width.
+        if !glyphs.is_empty() {
+            delta_x -= self.offset.0;
+        }
 
-        (glyphs, delta_x)
+        Ok((glyphs, delta_x.max(0) as u32))
     }
 
     /// Get the width of the given glyph
-    fn get_glyph_width(font: &Font, id: u32, size: f32) -> u32 {
+    fn get_glyph_width(font: &Font, id: u32, size: f32) -> Result<u32, FontError> {
         let metrics = font.metrics();
-        let advance = font.advance(id).unwrap();
-        (advance / metrics.units_per_em as f32 * size).x.ceil() as u32
+        let advance = font.advance(id)?;
+        Ok((advance / metrics.units_per_em as f32 * size).x.ceil() as u32)
     }
 
     /// Get the width of the given text
-    pub fn get_text_len(&self, text: &str) -> u32 {
-        self.layout(text, REGULAR).1
+    pub fn get_text_len(&self, text: &str) -> Result<u32, FontError> {
+        Ok(self.layout(text, REGULAR)?.1)
     }
 
     /// Draw the text to a image
     /// return the width of written text
-    pub fn draw_text_mut<I>(
+    ///
+    /// `style` accepts either a [`FontStyle`] or a [`FontSelector`] (e.g. a
+    /// weight/slant description, or a sub-style looked up by name).
+    pub fn draw_text_mut<I, S>(
         &self,
         image: &mut I,
         color: I::Pixel,
         x: u32,
         y: u32,
-        style: FontStyle,
+        style: S,
         text: &str,
-    ) -> u32
+    ) -> Result<u32, FontError>
     where
         I: GenericImage,
         <I::Pixel as Pixel>::Subpixel: ValueInto<f32> + Clamp<f32>,
+        S: Into<FontSelector>,
     {
-        let metrics = self.0[0].get_regular().metrics();
-        let offset =
-            (metrics.descent / metrics.units_per_em as f32 * self.0[0].size).round() as i32;
+        let metrics = self.fonts[0].get_regular().metrics();
+        let offset = (metrics.descent / metrics.units_per_em as f32
+            * self.fonts[0].size
+            * self.device_pixel_ratio)
+            .round() as i32;
 
-        let (glyphs, width) = self.layout(text, style);
+        let (glyphs, width) = self.layout(text, style)?;
 
         for glyph in glyphs {
-            glyph.draw(offset, |px, py, v| {
-                if v <= std::f32::EPSILON {
-                    return;
-                }
+            glyph.draw(offset, |px, py, pixel| {
                 let (x, y) = ((px + x as i32) as u32, (py + y as i32) as u32);
-                let pixel = image.get_pixel(x, y);
-                let weighted_color = weighted_sum(pixel, color, 1.0 - v, v);
+                let dst = image.get_pixel(x, y);
+
+                let weighted_color = match pixel {
+                    GlyphPixel::Coverage(v) => {
+                        // plain coverage: tint the requested text color, straight alpha
+                        weighted_sum(dst, color, 1.0 - v, v)
+                    }
+                    GlyphPixel::SubpixelCoverage { r, g, b } => {
+                        // LCD subpixel AA: blend the requested text color into each
+                        // destination channel independently, using that channel's
+                        // own coverage rather than a single scalar alpha.
+                        blend_coverage(dst, color, [r, g, b])
+                    }
+                };
                 image.put_pixel(x, y, weighted_color);
-            })
+            })?;
         }
 
-        width
+        Ok(width)
+    }
+}
+
+/// Blend `color` into `dst` one channel at a time, using an independent
+/// coverage value per channel (e.g. R/G/B coverage from LCD subpixel AA).
+/// `weighted_sum` can't express this: it applies a single weight to every
+/// channel, which is exactly what subpixel AA needs to not do. Channels past
+/// the third (e.g. alpha) reuse the last coverage value.
+fn blend_coverage<P>(dst: P, color: P, coverage: [f32; 3]) -> P
+where
+    P: Pixel,
+    P::Subpixel: ValueInto<f32> + Clamp<f32>,
+{
+    let mut out = dst;
+    let color_channels = color.channels().to_vec();
+    for (i, out_channel) in out.channels_mut().iter_mut().enumerate() {
+        let v = coverage[i.min(2)];
+        let d: f32 = (*out_channel).value_into().unwrap_or(0.0);
+        let s: f32 = color_channels[i].value_into().unwrap_or(0.0);
+        *out_channel = Clamp::clamp(d * (1.0 - v) + s * v);
     }
+    out
 }
 
 struct PositionedGlyph {
@@ -295,11 +614,29 @@ struct PositionedGlyph {
     size: f32,
     position: Point2D<i32>,
     raster_rect: Rect<i32>,
+    hinting: HintingOptions,
+    raster_options: RasterizationOptions,
+    /// Whether this glyph's font carries a color table. font-kit's public
+    /// rasterizer has no way to actually hand us those colors (see
+    /// [`has_color_table`]), so this only gates a debug note logged each time
+    /// such a glyph is drawn, not a different pixel format — the glyph still
+    /// renders as a coverage mask.
+    is_color: bool,
+}
+
+/// A single rasterized pixel of a glyph.
+enum GlyphPixel {
+    /// Plain antialiased coverage, to be tinted with the caller's text color.
+    Coverage(f32),
+    /// LCD subpixel-AA coverage: the R/G/B channels carry independent
+    /// coverage for each physical subpixel and must be blended into the
+    /// destination one channel at a time, not as a single scalar alpha.
+    SubpixelCoverage { r: f32, g: f32, b: f32 },
 }
 
 impl PositionedGlyph {
-    fn draw<O: FnMut(i32, i32, f32)>(&self, offset: i32, mut o: O) {
-        let mut canvas = Canvas::new(&self.raster_rect.size.to_u32(), Format::A8);
+    fn draw<O: FnMut(i32, i32, GlyphPixel)>(&self, offset: i32, mut o: O) -> Result<(), FontError> {
+        let mut canvas = Canvas::new(&self.raster_rect.size.to_u32(), Format::Rgba32);
 
         let origin = Point2D::new(
             -self.raster_rect.origin.x,
@@ -310,31 +647,61 @@ impl PositionedGlyph {
         // don't rasterize whitespace(https://github.com/pcwalton/font-kit/issues/7)
         // TODO: width of TAB ?
         if canvas.size != Size2D::new(0, 0) {
-            self.font
-                .rasterize_glyph(
-                    &mut canvas,
-                    self.id,
-                    self.size,
-                    &FontTransform::identity(),
-                    &origin,
-                    HintingOptions::None,
-                    RasterizationOptions::GrayscaleAa,
-                )
-                .unwrap();
+            self.font.rasterize_glyph(
+                &mut canvas,
+                self.id,
+                self.size,
+                &FontTransform::identity(),
+                &origin,
+                self.hinting,
+                self.raster_options,
+            )?;
         }
 
+        if self.is_color {
+            // font-kit's public rasterizer never emits real COLR/CBDT/sbix
+            // pixels into this canvas, so a color-capable glyph still only
+            // yields a coverage mask; render it like any other glyph.
+            debug!(
+                "glyph {} belongs to a color font, but font-kit exposes no way to \
+                 rasterize its real colors; rendering as a coverage mask instead",
+                self.id
+            );
+        }
+        let subpixel = self.raster_options == RasterizationOptions::SubpixelAa;
+
         for y in (0..self.raster_rect.size.height).rev() {
             let (row_start, row_end) =
                 (y as usize * canvas.stride, (y + 1) as usize * canvas.stride);
             let row = &canvas.pixels[row_start..row_end];
 
             for x in 0..self.raster_rect.size.width {
-                let val = f32::from(row[x as usize]) / 255.0;
+                let i = x as usize * 4;
+                let (r, g, b, a) = (row[i], row[i + 1], row[i + 2], row[i + 3]);
+
+                let pixel = if subpixel {
+                    if r == 0 && g == 0 && b == 0 {
+                        continue;
+                    }
+                    GlyphPixel::SubpixelCoverage {
+                        r: f32::from(r) / 255.0,
+                        g: f32::from(g) / 255.0,
+                        b: f32::from(b) / 255.0,
+                    }
+                } else {
+                    if a == 0 {
+                        continue;
+                    }
+                    GlyphPixel::Coverage(f32::from(a) / 255.0)
+                };
+
                 let px = self.position.x + x;
                 let py = self.position.y + y + offset;
 
-                o(px, py, val);
+                o(px, py, pixel);
             }
         }
+
+        Ok(())
     }
 }